@@ -10,16 +10,20 @@ use nameof::name_of;
     6. Conditional methods based on which traits a generic type implements.
 */
 
+// Associated types let the implementor fix the return type instead of the
+// caller choosing it, the same way `Iterator::Item` works. There's no
+// sensible default body anymore since `Self::Sound`/`Self::Cue` aren't known
+// here, so every implementor must provide one.
 trait Speak {
-    fn speak(&self) -> &str {
-        "Animal sound."
-    }
+    type Sound;
+
+    fn speak(&self) -> Self::Sound;
 }
 
 trait Recall {
-    fn recall(&self) -> &str {
-        "I'm coming back."
-    }
+    type Cue;
+
+    fn recall(&self) -> Self::Cue;
 }
 
 struct Dog {}
@@ -27,18 +31,74 @@ struct Dog {}
 struct Cat {}
 
 impl Speak for Dog {
-    fn speak(&self) -> &str {
+    type Sound = &'static str;
+
+    fn speak(&self) -> &'static str {
         "Bark"
     }
 }
 
 impl Speak for Cat {
-    fn speak(&self) -> &str {
+    type Sound = &'static str;
+
+    fn speak(&self) -> &'static str {
         "Meow"
     }
 }
 
-impl Recall for Dog {}
+// A richer recall result than a plain string, so callers can act on it
+// instead of just printing it.
+#[derive(Debug)]
+struct RecallResponse {
+    returning: bool,
+    distance_m: f32,
+}
+
+impl Recall for Dog {
+    type Cue = RecallResponse;
+
+    fn recall(&self) -> RecallResponse {
+        RecallResponse {
+            returning: true,
+            distance_m: 12.5,
+        }
+    }
+}
+
+// A decibel reading rather than a string, to show `Sound` isn't always text.
+struct Decibels(f64);
+
+impl std::fmt::Display for Decibels {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} dB", self.0)
+    }
+}
+
+struct Robot {}
+
+impl Speak for Robot {
+    type Sound = Decibels;
+
+    fn speak(&self) -> Decibels {
+        Decibels(82.5)
+    }
+}
+
+// T is a generic parameter the caller picks; T::Sound is an associated type
+// the implementor of Speak already fixed, so describe just forwards it.
+fn describe<T: Speak>(a: &T) -> T::Sound {
+    a.speak()
+}
+
+// Bounding the associated type itself (not T) is what lets us print the
+// result without knowing in advance what Self::Sound will be.
+fn describe_and_print<T>(a: &T)
+where
+    T: Speak,
+    T::Sound: std::fmt::Display,
+{
+    println!("{}", describe(a));
+}
 
 // Using traits to bind param type.
 // Limitation here is if we need multiple params to implement a trait,
@@ -78,25 +138,176 @@ struct Pet<T> {
 }
 
 impl<T: Recall> Pet<T> {
-    fn call_back(&self) -> &str {
+    fn call_back(&self) -> T::Cue {
         self.animal.recall()
     }
 }
 
 // Only define a method on pet if T implements a specific trait.
 impl<T: Speak> Pet<T> {
-    fn provoke(&self) -> &str {
+    fn provoke(&self) -> T::Sound {
         self.animal.speak()
     }
 }
 
+// Strategy pattern: the strategy itself decides what it hands back. An
+// associated type avoids threading a second generic parameter through
+// `Pet<T>` just to say what `run` returns -- `T::Output` already carries it.
+trait Strategy {
+    type Output;
+
+    fn execute(&self) -> Self::Output;
+}
+
+struct Fetch {
+    balls_retrieved: u32,
+}
+
+impl Strategy for Fetch {
+    type Output = u32;
+
+    fn execute(&self) -> u32 {
+        self.balls_retrieved
+    }
+}
+
+struct GuardStatus {
+    perimeter_breached: bool,
+}
+
+struct Guard {
+    perimeter_clear: bool,
+}
+
+impl Strategy for Guard {
+    type Output = GuardStatus;
+
+    fn execute(&self) -> GuardStatus {
+        GuardStatus {
+            perimeter_breached: !self.perimeter_clear,
+        }
+    }
+}
+
+impl<T: Strategy> Pet<T> {
+    fn run(&self) -> T::Output {
+        self.animal.execute()
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Ball;
+
+struct Whistle;
+
+// Rust has no function overloading, but a trait generic over its input lets
+// the same method name dispatch on the argument type instead: `interact`
+// resolves to a different impl depending on whether you pass a `Ball` or a
+// `Whistle`.
+trait Interact<Input> {
+    type Response;
+
+    fn interact(&self, input: Input) -> Self::Response;
+}
+
+impl Interact<Ball> for Dog {
+    type Response = &'static str;
+
+    fn interact(&self, _input: Ball) -> &'static str {
+        "Dog chases the ball."
+    }
+}
+
+impl Interact<Whistle> for Dog {
+    type Response = &'static str;
+
+    fn interact(&self, _input: Whistle) -> &'static str {
+        "Dog comes running."
+    }
+}
+
+// Splitting the same idea into a Copy-input trait and a reference-style
+// trait shows where the pseudo-overload breaks down: method lookup picks a
+// candidate by name and receiver type, not by argument type, so once a type
+// implements two traits that both name their method `interact`, every
+// dot-call to `interact` on that type is ambiguous -- not just the ones
+// with a matching input type -- and the compiler won't guess which one you
+// meant.
+trait InteractCopy<T: Copy> {
+    type Response;
+
+    fn interact(&self, input: T) -> Self::Response;
+}
+
+trait InteractRef<T> {
+    type Response;
+
+    fn interact(&self, input: T) -> Self::Response;
+}
+
+impl InteractCopy<Ball> for Dog {
+    type Response = &'static str;
+
+    fn interact(&self, _input: Ball) -> &'static str {
+        "Dog chases the ball (Copy path)."
+    }
+}
+
+impl InteractRef<Ball> for Dog {
+    type Response = &'static str;
+
+    fn interact(&self, _input: Ball) -> &'static str {
+        "Dog chases the ball (generic path)."
+    }
+}
+
+impl Recall for Robot {
+    type Cue = RecallResponse;
+
+    fn recall(&self) -> RecallResponse {
+        RecallResponse {
+            returning: true,
+            distance_m: 0.0,
+        }
+    }
+}
+
+// A supertrait bound: `TrainedPet: Speak + Recall` means only types that
+// already implement both can implement `TrainedPet`, and `report` gets to
+// call both supertrait methods for free.
+trait TrainedPet: Speak + Recall {
+    fn report(&self) -> String
+    where
+        Self::Sound: std::fmt::Display,
+        Self::Cue: std::fmt::Debug,
+    {
+        format!("Speak: {} | Recall: {:?}", self.speak(), self.recall())
+    }
+
+    // Most pets don't play by default; an implementor can override this.
+    fn play(&self) -> bool {
+        false
+    }
+}
+
+impl TrainedPet for Dog {
+    fn play(&self) -> bool {
+        true // Dogs always want to play, overriding the inherited default.
+    }
+}
+
+impl TrainedPet for Robot {} // No override: inherits `play` returning `false`.
+
+//impl TrainedPet for Cat {}
+// Doesn't compile: `TrainedPet: Speak + Recall`, and `Cat` never implements `Recall`.
+
 fn main() {
     let dog = Dog {};
     let dog2 = Dog {};
     let cat = Cat {};
 
     println!("{}", dog.speak());
-    println!("{}", dog.recall());
+    println!("{:?}", dog.recall());
     println!("{}", cat.speak());
 
     //println!("{}", cat.recall()); Doesn't exist, doesn't compile.
@@ -125,4 +336,88 @@ fn main() {
 
     pet2.provoke(); // This is the only method there is visibility for.
 
+    shelter::run();
+
+    describe_and_print(&Dog {});
+    describe_and_print(&Robot {});
+
+    let fetch_pet = Pet {
+        animal: Fetch { balls_retrieved: 3 },
+    };
+    println!("Balls retrieved: {}", fetch_pet.run());
+
+    let guard_pet = Pet {
+        animal: Guard {
+            perimeter_clear: true,
+        },
+    };
+    println!("Perimeter breached: {}", guard_pet.run().perimeter_breached);
+
+    let dog3 = Dog {};
+
+    //dog3.interact(Ball);
+    //dog3.interact(Whistle);
+    // Ambiguous either way: once `Interact<_>`, `InteractCopy<Ball>` and
+    // `InteractRef<Ball>` all define a method named `interact` for Dog,
+    // dot-call syntax can't tell them apart -- fully-qualified syntax names
+    // which trait's method we mean.
+    println!("{}", Interact::<Ball>::interact(&dog3, Ball));
+    println!("{}", Interact::<Whistle>::interact(&dog3, Whistle));
+    println!("{}", InteractCopy::<Ball>::interact(&dog3, Ball));
+    println!("{}", InteractRef::<Ball>::interact(&dog3, Ball));
+
+    println!("{}", dog3.report());
+    println!("Dog plays: {}", dog3.play());
+
+    let robot = Robot {};
+    println!("{}", robot.report());
+    println!("Robot plays: {}", robot.play());
+}
+
+// Everything above dispatches statically: every generic function gets a
+// monomorphized copy per concrete type, so a `Vec` of animals must be
+// homogeneous. Trait objects trade that for dynamic dispatch through a
+// vtable, letting us hold different concrete types behind the same slice.
+mod shelter {
+    use super::{Cat, Dog, Recall, RecallResponse, Speak};
+
+    // `Speak` is object safe: `speak` takes `&self` and isn't generic, so
+    // `dyn Speak` is a valid type. The associated type still has to be
+    // pinned down at the `dyn` site, though, since it's part of the trait's
+    // interface: `Dog` and `Cat` both fix `Sound = &'static str`, so they
+    // can share a `dyn Speak<Sound = &'static str>` object, but `Robot`
+    // (whose `Sound` is `Decibels`) couldn't join that same collection.
+    pub fn announce(animals: &[Box<dyn Speak<Sound = &'static str>>]) {
+        for animal in animals {
+            println!("Shelter animal says: {}", animal.speak());
+        }
+    }
+
+    pub fn run() {
+        let animals: Vec<Box<dyn Speak<Sound = &'static str>>> =
+            vec![Box::new(Dog {}), Box::new(Cat {})];
+
+        for animal in &animals {
+            println!("{}", animal.speak());
+        }
+
+        announce(&animals);
+
+        // Contrast with static dispatch: `take_two_to_play_trait_bound_syntax_where`
+        // forces the compiler to generate one function per (T, U) pair it's called
+        // with, so `animal_one` and `animal_two` are each some single concrete type
+        // known at compile time. A `&[&dyn Recall<Cue = RecallResponse>]` slice
+        // instead erases the concrete type of each element, dispatching `recall`
+        // through a vtable at runtime.
+        let dog = Dog {};
+        let dog2 = Dog {};
+        let recallers: &[&dyn Recall<Cue = RecallResponse>] = &[&dog, &dog2];
+        for animal in recallers {
+            println!("{:?}", animal.recall());
+        }
+
+        //let cats: Vec<Box<dyn Recall<Cue = RecallResponse>>> = vec![Box::new(Cat {})];
+        // Doesn't compile: `Cat` never implements `Recall`, so there's no impl to
+        // coerce into a `dyn Recall` trait object, object-safe or not.
+    }
 }
\ No newline at end of file